@@ -25,6 +25,8 @@ impl Value {
             ByRef(ptr) => mem.read_ptr(ptr),
             ByVal(PrimVal::Ptr(ptr)) |
             ByVal(PrimVal::FnPtr(ptr)) => Ok(ptr),
+            ByValPair(PrimVal::Ptr(ptr), _) |
+            ByValPair(PrimVal::FnPtr(ptr), _) => Ok(ptr),
             ByValPair(..) => unimplemented!(),
             ByVal(_other) => unimplemented!(),
         }
@@ -38,6 +40,48 @@ impl Value {
             ByVal(PrimVal::U16(u)) => Ok(u as u64),
             ByVal(PrimVal::U32(u)) => Ok(u as u64),
             ByVal(PrimVal::U64(u)) => Ok(u as u64),
+            ByValPair(PrimVal::U8(u), _) => Ok(u as u64),
+            ByValPair(PrimVal::U16(u), _) => Ok(u as u64),
+            ByValPair(PrimVal::U32(u), _) => Ok(u as u64),
+            ByValPair(PrimVal::U64(u), _) => Ok(u as u64),
+            ByValPair(..) => unimplemented!(),
+            ByVal(_other) => unimplemented!(),
+        }
+    }
+
+    /// Splits this value into its two `PrimVal` components, materializing a `ByRef` by reading
+    /// a checked-operation result `(value, overflow)` tuple out of memory: a `size`-byte value
+    /// followed immediately by a 1-byte overflow flag. `size` must be the size of the value half,
+    /// in bytes. Fat pointers are a different layout (two pointer-sized words) and should go
+    /// through `Value::metadata` instead.
+    pub(super) fn read_pair<'a, 'tcx: 'a>(&self, mem: &Memory<'a, 'tcx>, size: usize) -> EvalResult<'tcx, (PrimVal, PrimVal)> {
+        use self::Value::*;
+        match *self {
+            ByRef(ptr) => {
+                let val = mem.read_uint(ptr, size)?;
+                let a = match size {
+                    1 => PrimVal::U8(val as u8),
+                    2 => PrimVal::U16(val as u16),
+                    4 => PrimVal::U32(val as u32),
+                    8 => PrimVal::U64(val),
+                    _ => bug!("invalid size {} for a checked op value", size),
+                };
+                let flag = mem.read_uint(ptr.offset(size as isize), 1)?;
+                Ok((a, PrimVal::U8(flag as u8)))
+            }
+            ByValPair(a, b) => Ok((a, b)),
+            ByVal(_) => unimplemented!(),
+        }
+    }
+
+    pub(super) fn read_int<'a, 'tcx: 'a>(&self, mem: &Memory<'a, 'tcx>, size: usize) -> EvalResult<'tcx, i64> {
+        use self::Value::*;
+        match *self {
+            ByRef(ptr) => mem.read_int(ptr, size),
+            ByVal(PrimVal::I8(i)) => Ok(i as i64),
+            ByVal(PrimVal::I16(i)) => Ok(i as i64),
+            ByVal(PrimVal::I32(i)) => Ok(i as i64),
+            ByVal(PrimVal::I64(i)) => Ok(i as i64),
             ByValPair(..) => unimplemented!(),
             ByVal(_other) => unimplemented!(),
         }
@@ -51,11 +95,22 @@ impl Value {
         }
     }
 
-    pub(super) fn expect_vtable<'a, 'tcx: 'a>(&self, mem: &Memory<'a, 'tcx>) -> EvalResult<'tcx, Pointer> {
+    /// Extracts the metadata word of a fat pointer. For a `ByValPair` this is the second
+    /// component as it is stored, whether a vtable pointer or a slice length. For a `ByRef` the
+    /// second pointer-sized word is read as a pointer, so that case yields a `&dyn Trait`'s vtable
+    /// and does not give a `&[T]`'s length; `expect_slice_len` reads a `ByRef`'s length itself.
+    pub(super) fn metadata<'a, 'tcx: 'a>(&self, mem: &Memory<'a, 'tcx>) -> EvalResult<'tcx, PrimVal> {
         use self::Value::*;
         match *self {
-            ByRef(ptr) => mem.read_ptr(ptr.offset(mem.pointer_size() as isize)),
-            ByValPair(_, PrimVal::Ptr(vtable)) => Ok(vtable),
+            ByRef(ptr) => Ok(PrimVal::Ptr(mem.read_ptr(ptr.offset(mem.pointer_size() as isize))?)),
+            ByValPair(_, meta) => Ok(meta),
+            ByVal(_) => unimplemented!(),
+        }
+    }
+
+    pub(super) fn expect_vtable<'a, 'tcx: 'a>(&self, mem: &Memory<'a, 'tcx>) -> EvalResult<'tcx, Pointer> {
+        match self.metadata(mem)? {
+            PrimVal::Ptr(vtable) => Ok(vtable),
             _ => unimplemented!(),
         }
     }
@@ -64,11 +119,13 @@ impl Value {
         use self::Value::*;
         match *self {
             ByRef(ptr) => mem.read_usize(ptr.offset(mem.pointer_size() as isize)),
-            ByValPair(_, PrimVal::U8(len)) => Ok(len as u64),
-            ByValPair(_, PrimVal::U16(len)) => Ok(len as u64),
-            ByValPair(_, PrimVal::U32(len)) => Ok(len as u64),
-            ByValPair(_, PrimVal::U64(len)) => Ok(len),
-            _ => unimplemented!(),
+            _ => match self.metadata(mem)? {
+                PrimVal::U8(len) => Ok(len as u64),
+                PrimVal::U16(len) => Ok(len as u64),
+                PrimVal::U32(len) => Ok(len as u64),
+                PrimVal::U64(len) => Ok(len),
+                _ => unimplemented!(),
+            },
         }
     }
 }